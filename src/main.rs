@@ -3,41 +3,210 @@ use std::collections::HashSet;
 use levenshtein::levenshtein;
 use rust_xlsxwriter::{Color, Format, FormatAlign, FormatBorder, Workbook};
 
+/// Tile-similarity metric used by [`check_tiles`].
+///
+/// `Levenshtein` and `Damerau` report an edit distance (lower is more similar, and
+/// `--dist` is a maximum). `JaroWinkler` and `Norm` report a similarity in `0..=1`
+/// (higher is more similar, and `--dist` is a minimum).
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Metric {
+    Levenshtein,
+    Damerau,
+    JaroWinkler,
+    Norm,
+}
+
+impl Metric {
+    fn parse(s: &str) -> Self {
+        match s {
+            "levenshtein" => Metric::Levenshtein,
+            "damerau" => Metric::Damerau,
+            "jaro-winkler" => Metric::JaroWinkler,
+            "norm" => Metric::Norm,
+            other => panic!("Unknown metric: {other}"),
+        }
+    }
+
+    /// Whether a higher score means *more* similar (as opposed to a lower edit distance).
+    fn is_similarity(self) -> bool {
+        matches!(self, Metric::JaroWinkler | Metric::Norm)
+    }
+
+    /// The `--dist=` threshold to use when the user didn't pass one explicitly:
+    /// a maximum edit distance for the edit-distance metrics, a minimum
+    /// similarity for the `0..=1` metrics.
+    fn default_threshold(self) -> f64 {
+        if self.is_similarity() {
+            0.9
+        } else {
+            3.0
+        }
+    }
+
+    fn score(self, a: &str, b: &str) -> f64 {
+        match self {
+            Metric::Levenshtein => levenshtein(a, b) as f64,
+            Metric::Damerau => damerau_levenshtein(a, b) as f64,
+            Metric::JaroWinkler => jaro_winkler(a, b),
+            Metric::Norm => {
+                let max_len = a.chars().count().max(b.chars().count());
+                if max_len == 0 {
+                    return 1.0;
+                }
+                1.0 - levenshtein(a, b) as f64 / max_len as f64
+            }
+        }
+    }
+}
+
+/// Output format(s) requested via `--format=`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum OutputFormat {
+    Xlsx,
+    Png,
+    Both,
+}
+
+impl OutputFormat {
+    fn parse(s: &str) -> Self {
+        match s {
+            "xlsx" => OutputFormat::Xlsx,
+            "png" => OutputFormat::Png,
+            "both" => OutputFormat::Both,
+            other => panic!("Unknown format: {other}"),
+        }
+    }
+
+    fn wants_xlsx(self) -> bool {
+        matches!(self, OutputFormat::Xlsx | OutputFormat::Both)
+    }
+
+    fn wants_png(self) -> bool {
+        matches!(self, OutputFormat::Png | OutputFormat::Both)
+    }
+}
+
 fn main() {
     let mut free_square = "FREE SQUARE".to_string();
-    let mut distance_limit = 3;
+    let mut distance_limit = None;
+    let mut metric = Metric::Levenshtein;
+    let mut format = OutputFormat::Xlsx;
     let mut people = vec!["Alice".to_string(), "Bob".to_string()];
+    let mut preview = false;
+    let mut size = 5usize;
+    let mut cards = 1usize;
 
     for arg in std::env::args().skip(1) {
         if let Some(d) = arg.strip_prefix("--dist=") {
-            distance_limit = d.parse().unwrap();
+            distance_limit = Some(d.parse().unwrap());
         } else if let Some(pp) = arg.strip_prefix("--people=") {
             people = pp.split(',').map(|s| s.trim().to_string()).collect();
+        } else if let Some(m) = arg.strip_prefix("--metric=") {
+            metric = Metric::parse(m);
+        } else if let Some(f) = arg.strip_prefix("--format=") {
+            format = OutputFormat::parse(f);
+        } else if let Some(s) = arg.strip_prefix("--size=") {
+            size = s.parse().unwrap();
+            assert!(size >= 2, "--size must be at least 2");
+        } else if let Some(k) = arg.strip_prefix("--cards=") {
+            cards = k.parse().unwrap();
+            assert!(cards >= 1, "--cards must be at least 1");
+        } else if arg == "--preview" {
+            preview = true;
         } else if arg == "-h" {
-            println!("Usage: bingo-card [--dist=N] [--people=NAME1,NAME2,...] [FREE_SQUARE_TEXT]");
+            println!("Usage: bingo-card [--dist=N] [--metric=METRIC] [--format=FORMAT] [--size=N] [--cards=K] [--preview] [--people=NAME1,NAME2,...] [FREE_SQUARE_TEXT]");
             println!();
             println!("  --dist=N               Set the minimum Levenshtein distance between tiles");
-            println!("  --people=NAME1,NAME2  Comma-separated list of people to generate cards for");
-            println!("  FREE_SQUARE_TEXT      Text to use for the free square (default: 'FREE SQUARE')");
+            println!("                         (for jaro-winkler/norm: the similarity at or above");
+            println!("                         which tiles are reported as similar);");
+            println!("                         defaults to 3 for edit-distance metrics, 0.9 for");
+            println!("                         similarity metrics");
+            println!("  --metric=METRIC        Similarity metric to use: levenshtein (default),");
+            println!("                         damerau, jaro-winkler, norm");
+            println!("  --format=FORMAT        Output format: xlsx (default), png, or both");
+            println!("  --size=N               Card dimensions: N x N squares (default: 5)");
+            println!("  --cards=K              Number of distinct cards to generate per person (default: 1)");
+            println!("  --preview              Preview cards in the terminal before writing files");
+            println!(
+                "  --people=NAME1,NAME2  Comma-separated list of people to generate cards for"
+            );
+            println!(
+                "  FREE_SQUARE_TEXT      Text to use for the free square (default: 'FREE SQUARE')"
+            );
             return;
         } else if !arg.starts_with('-') {
             free_square = arg;
         }
     }
 
-    let mut workbook = Workbook::new();
     let tiles = load_tiles();
 
-    check_tiles(&tiles, distance_limit);
+    let distance_limit = distance_limit.unwrap_or_else(|| metric.default_threshold());
+    check_tiles(&tiles, distance_limit, metric);
+
+    let pool = tiles.iter().collect::<Vec<_>>();
+    let mut rng = rand::rng();
+
+    let needed = tiles_per_card(size);
+    assert!(
+        pool.len() >= needed,
+        "tiles.txt has {} tiles, but a {size}x{size} card needs at least {needed}",
+        pool.len()
+    );
+
+    // one (label, selection) entry per person per card, e.g. "Alice" or "Alice #2":
+    let labels: Vec<String> = people
+        .iter()
+        .flat_map(|person| {
+            (0..cards).map(move |i| {
+                if cards == 1 {
+                    person.clone()
+                } else {
+                    format!("{person} #{}", i + 1)
+                }
+            })
+        })
+        .collect();
+
+    let dist = build_distance_matrix(&pool);
+
+    let mut selections: Vec<Vec<String>> = labels
+        .iter()
+        .map(|_| select_diverse_tiles(&pool, &dist, needed, &mut rng))
+        .collect();
 
-    for person in &people {
-        generate_for_person(&mut workbook, person, &tiles, &free_square);
+    if preview {
+        run_preview(
+            &labels,
+            &mut selections,
+            &free_square,
+            &pool,
+            &dist,
+            &mut rng,
+            size,
+        );
     }
 
-    workbook.save("bingo.xlsx").unwrap();
+    let mut workbook = Workbook::new();
+    let font = format.wants_png().then(load_font);
+
+    for (label, selected) in labels.iter().zip(selections.iter()) {
+        if format.wants_xlsx() {
+            generate_for_person(&mut workbook, label, selected, &free_square, size);
+        }
+        if format.wants_png() {
+            let img =
+                render_card_image(label, selected, &free_square, font.as_ref().unwrap(), size);
+            img.save(format!("bingo-{label}.png")).unwrap();
+        }
+    }
+
+    if format.wants_xlsx() {
+        workbook.save("bingo.xlsx").unwrap();
+    }
 }
 
-fn check_tiles(tiles: &HashSet<String>, distance_limit: usize) {
+fn check_tiles(tiles: &HashSet<String>, distance_limit: f64, metric: Metric) {
     let tiles = tiles.iter().collect::<Vec<_>>();
 
     for i in 0..tiles.len() - 1 {
@@ -50,10 +219,15 @@ fn check_tiles(tiles: &HashSet<String>, distance_limit: usize) {
                 continue;
             }
 
-            let dist = levenshtein(a, b);
+            let score = metric.score(a, b);
+            let flagged = if metric.is_similarity() {
+                score >= distance_limit
+            } else {
+                score <= distance_limit
+            };
 
-            if dist <= distance_limit {
-                println!("Similar tiles (dist={dist}):");
+            if flagged {
+                println!("Similar tiles ({metric:?} score={score}):");
                 println!("  1: {a}");
                 println!("  2: {b}");
             }
@@ -61,6 +235,155 @@ fn check_tiles(tiles: &HashSet<String>, distance_limit: usize) {
     }
 }
 
+/// Damerau-Levenshtein edit distance: Levenshtein plus adjacent-transposition as a
+/// single edit.
+fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (m, n) = (a.len(), b.len());
+
+    let mut d = vec![vec![0usize; n + 1]; m + 1];
+    for (i, row) in d.iter_mut().enumerate().take(m + 1) {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=m {
+        for j in 1..=n {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    d[m][n]
+}
+
+/// Jaro similarity in `0..=1`.
+fn jaro(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (a_len, b_len) = (a.len(), b.len());
+
+    if a_len == 0 && b_len == 0 {
+        return 1.0;
+    }
+    if a_len == 0 || b_len == 0 {
+        return 0.0;
+    }
+
+    let window = (a_len.max(b_len) / 2).saturating_sub(1);
+
+    let mut a_matched = vec![false; a_len];
+    let mut b_matched = vec![false; b_len];
+    let mut matches = 0;
+
+    for i in 0..a_len {
+        let lo = i.saturating_sub(window);
+        let hi = (i + window + 1).min(b_len);
+        for j in lo..hi {
+            if !b_matched[j] && a[i] == b[j] {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let mut transpositions = 0;
+    let mut b_iter = (0..b_len).filter(|&j| b_matched[j]);
+    for i in 0..a_len {
+        if !a_matched[i] {
+            continue;
+        }
+        if let Some(j) = b_iter.next() {
+            if a[i] != b[j] {
+                transpositions += 1;
+            }
+        }
+    }
+    let transpositions = transpositions / 2;
+
+    let m = matches as f64;
+    (m / a_len as f64 + m / b_len as f64 + (m - transpositions as f64) / m) / 3.0
+}
+
+/// Jaro-Winkler similarity in `0..=1`: Jaro similarity boosted by a shared prefix
+/// (capped at 4 characters).
+fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let j = jaro(a, b);
+
+    let prefix_len = a
+        .chars()
+        .zip(b.chars())
+        .take(4)
+        .take_while(|(ca, cb)| ca == cb)
+        .count();
+
+    j + prefix_len as f64 * 0.1 * (1.0 - j)
+}
+
+#[cfg(test)]
+mod metric_tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    #[test]
+    fn damerau_counts_a_transposition_as_one_edit() {
+        // "ab" -> "ba" is a single adjacent swap, not two substitutions.
+        assert_eq!(damerau_levenshtein("ab", "ba"), 1);
+        assert_eq!(levenshtein::levenshtein("ab", "ba"), 2);
+    }
+
+    #[test]
+    fn damerau_matches_levenshtein_without_transpositions() {
+        assert_eq!(damerau_levenshtein("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn jaro_known_vectors() {
+        assert!(close(jaro("MARTHA", "MARHTA"), 0.944));
+        assert!(close(jaro("DIXON", "DICKSONX"), 0.767));
+    }
+
+    #[test]
+    fn jaro_winkler_known_vectors() {
+        assert!(close(jaro_winkler("MARTHA", "MARHTA"), 0.961));
+        assert!(close(jaro_winkler("DIXON", "DICKSONX"), 0.813));
+    }
+
+    #[test]
+    fn jaro_identical_strings_is_one() {
+        assert_eq!(jaro("SUMO", "SUMO"), 1.0);
+        assert_eq!(jaro_winkler("SUMO", "SUMO"), 1.0);
+    }
+
+    #[test]
+    fn default_threshold_matches_each_metric_scale() {
+        // Similarity metrics score in 0..=1, so their default must be a
+        // minimum similarity, not the edit-distance default.
+        assert_eq!(Metric::Levenshtein.default_threshold(), 3.0);
+        assert_eq!(Metric::Damerau.default_threshold(), 3.0);
+        assert_eq!(Metric::JaroWinkler.default_threshold(), 0.9);
+        assert_eq!(Metric::Norm.default_threshold(), 0.9);
+    }
+}
+
 /// Loads lines from 'tiles.txt' with one tile per line.
 ///
 /// Lines may include a slash and 'n' that will be turned into a newline for cleaner, wrapped
@@ -76,7 +399,124 @@ fn load_tiles() -> HashSet<String> {
         .collect()
 }
 
-fn generate_for_person(wb: &mut Workbook, name: &str, tiles: &HashSet<String>, free_square: &str) {
+/// Computes the full pairwise Levenshtein distance matrix over `pool`, indexed
+/// `[i][j]`. Building this once and reusing it across every person/card's
+/// [`select_diverse_tiles`] call is what keeps per-card selection cheap.
+fn build_distance_matrix(pool: &[&String]) -> Vec<Vec<usize>> {
+    let n = pool.len();
+    let mut dist = vec![vec![0usize; n]; n];
+    for i in 0..n {
+        for j in i + 1..n {
+            let d = levenshtein(pool[i], pool[j]);
+            dist[i][j] = d;
+            dist[j][i] = d;
+        }
+    }
+    dist
+}
+
+/// Selects `count` tiles from `pool` via farthest-first (max-min dispersion) on
+/// Levenshtein distance: seed with one random tile, then repeatedly add whichever
+/// remaining tile has the largest minimum distance to the tiles already chosen.
+///
+/// This keeps a single card's squares visually distinct even when the global
+/// `check_tiles` pass only guards against near-duplicates across the whole pool.
+/// `dist` must be the matrix from [`build_distance_matrix`] over the same `pool`.
+fn select_diverse_tiles(
+    pool: &[&String],
+    dist: &[Vec<usize>],
+    count: usize,
+    rng: &mut impl rand::Rng,
+) -> Vec<String> {
+    assert!(
+        pool.len() >= count,
+        "tile pool has {} tiles, need at least {count}",
+        pool.len()
+    );
+
+    let n = pool.len();
+    let mut remaining: Vec<usize> = (0..n).collect();
+    let seed = remaining.remove(rand::Rng::random_range(rng, 0..remaining.len()));
+    let mut selected = vec![seed];
+
+    while selected.len() < count {
+        let (best_idx, _) = remaining
+            .iter()
+            .enumerate()
+            .map(|(ri, &candidate)| {
+                let min_dist = selected.iter().map(|&s| dist[candidate][s]).min().unwrap();
+                (ri, min_dist)
+            })
+            .max_by_key(|&(_, min_dist)| min_dist)
+            .unwrap();
+
+        selected.push(remaining.remove(best_idx));
+    }
+
+    selected.into_iter().map(|i| pool[i].clone()).collect()
+}
+
+#[cfg(test)]
+mod selection_tests {
+    use super::*;
+
+    #[test]
+    fn selects_the_requested_count_with_no_duplicates() {
+        let owned: Vec<String> = vec!["SUMO", "SUMOS", "WRESTLING", "DOHYO", "SHIKO", "MAWASHI"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let pool: Vec<&String> = owned.iter().collect();
+        let dist = build_distance_matrix(&pool);
+        let mut rng = rand::rng();
+
+        let selected = select_diverse_tiles(&pool, &dist, 4, &mut rng);
+
+        assert_eq!(selected.len(), 4);
+        let unique: HashSet<&String> = selected.iter().collect();
+        assert_eq!(unique.len(), 4);
+    }
+
+    #[test]
+    fn prefers_the_farthest_tile_over_a_near_duplicate() {
+        // "SUMO", "SUMOS" and "SUMOX" are near-identical; whichever tile seeds
+        // the 3-tile card, farthest-first picks the clearly distinct "DOHYO"
+        // before a second close variant.
+        let owned: Vec<String> = vec!["SUMO", "SUMOS", "DOHYO", "SUMOX"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let pool: Vec<&String> = owned.iter().collect();
+        let dist = build_distance_matrix(&pool);
+        let mut rng = rand::rng();
+
+        let selected = select_diverse_tiles(&pool, &dist, 3, &mut rng);
+
+        assert_eq!(selected.len(), 3);
+        assert!(selected.contains(&"DOHYO".to_string()));
+    }
+}
+
+/// Number of non-free tiles a `size`x`size` card needs: the grid is fully
+/// populated, minus the one free square at dead center when `size` is odd (an
+/// even-sized grid has no true center, so it gets no free square).
+fn tiles_per_card(size: usize) -> usize {
+    size * size - usize::from(size % 2 == 1)
+}
+
+/// The index of the free square's row, which is also its column, or `None` for
+/// an even `size` (no true center square to place it on).
+fn center_cell(size: usize) -> Option<usize> {
+    (size % 2 == 1).then_some(size / 2)
+}
+
+fn generate_for_person(
+    wb: &mut Workbook,
+    name: &str,
+    selected: &[String],
+    free_square: &str,
+    size: usize,
+) {
     const HEADER_ROWS: u32 = 2;
     const LEFT_COLS: u16 = 1;
     const CELL_DIMENSIONS: u16 = 150;
@@ -96,10 +536,17 @@ fn generate_for_person(wb: &mut Workbook, name: &str, tiles: &HashSet<String>, f
     // set the header
     let header_txt = format!("SUMO BINGO! - {name}");
     sheet
-        .merge_range(1, LEFT_COLS, 1, 4 + LEFT_COLS, &header_txt, &dotted_fmt)
+        .merge_range(
+            1,
+            LEFT_COLS,
+            1,
+            size as u16 - 1 + LEFT_COLS,
+            &header_txt,
+            &dotted_fmt,
+        )
         .unwrap();
 
-    for i in 0..5 {
+    for i in 0..size as u16 {
         sheet
             .set_column_width_pixels(i + LEFT_COLS, CELL_DIMENSIONS)
             .unwrap();
@@ -108,18 +555,16 @@ fn generate_for_person(wb: &mut Workbook, name: &str, tiles: &HashSet<String>, f
             .unwrap();
     }
 
-    let mut tiles = tiles.iter().collect::<Vec<_>>();
+    let center = center_cell(size);
+    let mut selected = selected.iter();
 
-    // randomize the order of `tiles`:
-    use rand::seq::SliceRandom as _;
-    let mut rng = rand::rng();
-    tiles.shuffle(&mut rng);
-
-    for (i, tile) in tiles.iter().enumerate() {
-        let row = i as u32 % 5 + HEADER_ROWS;
-        let col = i as u16 / 5 + LEFT_COLS;
+    for i in 0..size * size {
+        let row_idx = i % size;
+        let col_idx = i / size;
+        let row = row_idx as u32 + HEADER_ROWS;
+        let col = col_idx as u16 + LEFT_COLS;
 
-        if row == 2 + HEADER_ROWS && col == 2 + LEFT_COLS {
+        if center == Some(row_idx) && center == Some(col_idx) {
             // center square
             let center_fmt = Format::new()
                 .set_bold()
@@ -134,9 +579,453 @@ fn generate_for_person(wb: &mut Workbook, name: &str, tiles: &HashSet<String>, f
                 .write_string_with_format(row, col, free_square, &center_fmt)
                 .unwrap();
         } else {
+            let tile = selected.next().expect("enough diverse tiles were selected");
             sheet
-                .write_string_with_format(row, col, *tile, &dotted_fmt)
+                .write_string_with_format(row, col, tile, &dotted_fmt)
+                .unwrap();
+        }
+    }
+}
+
+/// Loads the TrueType font used to render `--format=png` cards, from `font.ttf` in
+/// the working directory (alongside `tiles.txt`).
+fn load_font() -> ab_glyph::FontArc {
+    let bytes = std::fs::read("font.ttf").expect("font.ttf not found");
+    ab_glyph::FontArc::try_from_vec(bytes).expect("font.ttf is not a valid TrueType font")
+}
+
+/// Renders one person's `size`x`size` card directly to a raster image, for camera-ready
+/// printing without opening Excel.
+fn render_card_image(
+    name: &str,
+    selected: &[String],
+    free_square: &str,
+    font: &ab_glyph::FontArc,
+    size: usize,
+) -> image::RgbaImage {
+    use ab_glyph::PxScale;
+    use imageproc::drawing::{draw_filled_rect_mut, draw_hollow_rect_mut};
+    use imageproc::rect::Rect;
+
+    const CELL_DIMENSIONS: u32 = 150;
+    const HEADER_HEIGHT: u32 = 60;
+    const BORDER_COLOR: image::Rgba<u8> = image::Rgba([0x33, 0x33, 0x33, 0xff]);
+    const FREE_BG: image::Rgba<u8> = image::Rgba([0x22, 0x22, 0x22, 0xff]);
+    const WHITE: image::Rgba<u8> = image::Rgba([0xff, 0xff, 0xff, 0xff]);
+    const BLACK: image::Rgba<u8> = image::Rgba([0x00, 0x00, 0x00, 0xff]);
+
+    let size = size as u32;
+    let width = CELL_DIMENSIONS * size;
+    let height = HEADER_HEIGHT + CELL_DIMENSIONS * size;
+    let mut img = image::RgbaImage::from_pixel(width, height, WHITE);
+
+    let header_scale = PxScale::from(28.0);
+    draw_wrapped_centered(
+        &mut img,
+        font,
+        header_scale,
+        BLACK,
+        &format!("SUMO BINGO! - {name}"),
+        0,
+        0,
+        width,
+        HEADER_HEIGHT,
+    );
+
+    let mut selected = selected.iter();
+    let cell_scale = PxScale::from(18.0);
+    let center = center_cell(size as usize).map(|c| c as u32);
+
+    for row in 0..size {
+        for col in 0..size {
+            let x = (col * CELL_DIMENSIONS) as i32;
+            let y = (HEADER_HEIGHT + row * CELL_DIMENSIONS) as i32;
+
+            draw_hollow_rect_mut(
+                &mut img,
+                Rect::at(x, y).of_size(CELL_DIMENSIONS, CELL_DIMENSIONS),
+                BORDER_COLOR,
+            );
+
+            if center == Some(row) && center == Some(col) {
+                draw_filled_rect_mut(
+                    &mut img,
+                    Rect::at(x + 1, y + 1).of_size(CELL_DIMENSIONS - 2, CELL_DIMENSIONS - 2),
+                    FREE_BG,
+                );
+                draw_wrapped_centered(
+                    &mut img,
+                    font,
+                    cell_scale,
+                    WHITE,
+                    free_square,
+                    x,
+                    y,
+                    CELL_DIMENSIONS,
+                    CELL_DIMENSIONS,
+                );
+            } else {
+                let tile = selected.next().expect("enough diverse tiles were selected");
+                draw_wrapped_centered(
+                    &mut img,
+                    font,
+                    cell_scale,
+                    BLACK,
+                    tile,
+                    x,
+                    y,
+                    CELL_DIMENSIONS,
+                    CELL_DIMENSIONS,
+                );
+            }
+        }
+    }
+
+    img
+}
+
+/// Word-wraps `text` to fit within `max_width` pixels (honoring any existing `\n`
+/// line breaks from [`load_tiles`]) and draws it centered, both horizontally and
+/// vertically, within the `(x, y, w, h)` cell.
+#[allow(clippy::too_many_arguments)]
+fn draw_wrapped_centered(
+    img: &mut image::RgbaImage,
+    font: &ab_glyph::FontArc,
+    scale: ab_glyph::PxScale,
+    color: image::Rgba<u8>,
+    text: &str,
+    x: i32,
+    y: i32,
+    w: u32,
+    h: u32,
+) {
+    use imageproc::drawing::{draw_text_mut, text_size};
+
+    const PADDING: u32 = 6;
+    let max_width = w.saturating_sub(PADDING * 2);
+
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let candidate = if line.is_empty() {
+                word.to_string()
+            } else {
+                format!("{line} {word}")
+            };
+            let (width, _) = text_size(scale, font, &candidate);
+            if width > max_width && !line.is_empty() {
+                lines.push(line);
+                line = word.to_string();
+            } else {
+                line = candidate;
+            }
+        }
+        lines.push(line);
+    }
+
+    let line_height = text_size(scale, font, "Ag").1.max(1) as u32 + 2;
+    let block_height = line_height * lines.len() as u32;
+    let mut cursor_y = y + (h.saturating_sub(block_height) / 2) as i32;
+
+    for line in &lines {
+        let (line_width, _) = text_size(scale, font, line);
+        let line_x = x + (w.saturating_sub(line_width) / 2) as i32;
+        draw_text_mut(img, color, line_x, cursor_y, scale, font, line);
+        cursor_y += line_height as i32;
+    }
+}
+
+/// A single addressable terminal cell: the character drawn there plus the colors
+/// and attributes it's drawn with.
+#[derive(Clone, Copy)]
+struct Cell {
+    ch: char,
+    fg: crossterm::style::Color,
+    bg: crossterm::style::Color,
+    bold: bool,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Cell {
+            ch: ' ',
+            fg: crossterm::style::Color::Reset,
+            bg: crossterm::style::Color::Reset,
+            bold: false,
+        }
+    }
+}
+
+/// A `(x, y)`-addressed grid of [`Cell`]s, used to lay out a card preview before
+/// it's painted to the terminal.
+struct Buffer {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+}
+
+impl Buffer {
+    fn new(width: usize, height: usize) -> Self {
+        Buffer {
+            width,
+            height,
+            cells: vec![Cell::default(); width * height],
+        }
+    }
+
+    fn put_char(
+        &mut self,
+        x: usize,
+        y: usize,
+        ch: char,
+        fg: crossterm::style::Color,
+        bg: crossterm::style::Color,
+        bold: bool,
+    ) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        self.cells[y * self.width + x] = Cell { ch, fg, bg, bold };
+    }
+
+    fn put_str(
+        &mut self,
+        x: usize,
+        y: usize,
+        s: &str,
+        fg: crossterm::style::Color,
+        bg: crossterm::style::Color,
+        bold: bool,
+    ) {
+        for (i, ch) in s.chars().enumerate() {
+            self.put_char(x + i, y, ch, fg, bg, bold);
+        }
+    }
+}
+
+/// Word-wraps `text` to fit within `max_width` terminal columns, honoring any
+/// existing `\n` line breaks from [`load_tiles`].
+fn wrap_text_chars(text: &str, max_width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let candidate = if line.is_empty() {
+                word.to_string()
+            } else {
+                format!("{line} {word}")
+            };
+            if candidate.chars().count() > max_width && !line.is_empty() {
+                lines.push(line);
+                line = word.to_string();
+            } else {
+                line = candidate;
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// The box-drawing character for the grid-line intersection at border row `r`
+/// (0..=rows) and border column `c` (0..=cols).
+fn intersection_char(r: usize, rows: usize, c: usize, cols: usize) -> char {
+    match (r == 0, r == rows, c == 0, c == cols) {
+        (true, _, true, _) => '┌',
+        (true, _, _, true) => '┐',
+        (_, true, true, _) => '└',
+        (_, true, _, true) => '┘',
+        (true, _, _, _) => '┬',
+        (_, true, _, _) => '┴',
+        (_, _, true, _) => '├',
+        (_, _, _, true) => '┤',
+        _ => '┼',
+    }
+}
+
+/// Renders one person's `size`x`size` card as a terminal [`Buffer`], sized to fit
+/// within `term_w`x`term_h` columns/rows.
+fn render_preview_buffer(
+    name: &str,
+    selected: &[String],
+    free_square: &str,
+    term_w: usize,
+    term_h: usize,
+    size: usize,
+) -> Buffer {
+    use crossterm::style::Color;
+
+    let cols = size;
+    let rows = size;
+    const HEADER_ROWS: usize = 2;
+    const BORDER: Color = Color::DarkGrey;
+
+    let avail_w = term_w.saturating_sub(cols + 1).max(cols * 4);
+    let avail_h = term_h
+        .saturating_sub(HEADER_ROWS + rows + 1 + 2)
+        .max(rows * 2);
+
+    let cell_w = (avail_w / cols).max(6);
+    let cell_h = (avail_h / rows).max(3);
+
+    let grid_w = cell_w * cols + cols + 1;
+    let grid_h = cell_h * rows + rows + 1;
+
+    let mut buf = Buffer::new(grid_w.max(name.len() + 14), HEADER_ROWS + grid_h);
+
+    let header = format!("SUMO BINGO! - {name}");
+    buf.put_str(0, 0, &header, Color::White, Color::Reset, true);
+
+    for r in 0..=rows {
+        let y = HEADER_ROWS + r * (cell_h + 1);
+        for c in 0..=cols {
+            let x = c * (cell_w + 1);
+            buf.put_char(
+                x,
+                y,
+                intersection_char(r, rows, c, cols),
+                BORDER,
+                Color::Reset,
+                false,
+            );
+            if c < cols {
+                for dx in 1..=cell_w {
+                    buf.put_char(x + dx, y, '─', BORDER, Color::Reset, false);
+                }
+            }
+        }
+        if r < rows {
+            for dy in 1..=cell_h {
+                for c in 0..=cols {
+                    let x = c * (cell_w + 1);
+                    buf.put_char(x, y + dy, '│', BORDER, Color::Reset, false);
+                }
+            }
+        }
+    }
+
+    let mut selected = selected.iter();
+    let interior_w = cell_w.saturating_sub(2).max(1);
+    let center = center_cell(size);
+
+    for row in 0..rows {
+        for col in 0..cols {
+            let cell_x = col * (cell_w + 1) + 1;
+            let cell_y = HEADER_ROWS + row * (cell_h + 1) + 1;
+
+            let (text, fg, bg, bold) = if center == Some(row) && center == Some(col) {
+                (free_square.to_string(), Color::Black, Color::Grey, true)
+            } else {
+                let tile = selected.next().expect("enough diverse tiles were selected");
+                (tile.clone(), Color::Reset, Color::Reset, false)
+            };
+
+            let lines = wrap_text_chars(&text, interior_w);
+            let start_y = cell_y + cell_h.saturating_sub(lines.len()) / 2;
+            for (i, line) in lines.iter().enumerate() {
+                let start_x = cell_x + interior_w.saturating_sub(line.chars().count()) / 2;
+                buf.put_str(start_x, start_y + i, line, fg, bg, bold);
+            }
+        }
+    }
+
+    buf
+}
+
+/// Shows an interactive terminal preview of each card before any files are
+/// written: left/right arrows switch cards, `r` reshuffles the current card,
+/// and Enter/`q` commits the shown layouts and exits.
+fn run_preview(
+    labels: &[String],
+    selections: &mut [Vec<String>],
+    free_square: &str,
+    pool: &[&String],
+    dist: &[Vec<usize>],
+    rng: &mut impl rand::Rng,
+    size: usize,
+) {
+    use crossterm::{
+        cursor,
+        event::{self, Event, KeyCode},
+        execute, queue,
+        style::{Print, ResetColor, SetAttribute, SetBackgroundColor, SetForegroundColor},
+        terminal::{self, ClearType},
+    };
+    use std::io::{stdout, Write as _};
+
+    let mut out = stdout();
+
+    if let Err(e) = terminal::enable_raw_mode() {
+        eprintln!("`--preview` requires an interactive terminal ({e})");
+        std::process::exit(1);
+    }
+    if let Err(e) = execute!(out, terminal::EnterAlternateScreen, cursor::Hide) {
+        eprintln!("`--preview` requires an interactive terminal ({e})");
+        let _ = terminal::disable_raw_mode();
+        std::process::exit(1);
+    }
+
+    let mut idx = 0usize;
+
+    loop {
+        let (term_w, term_h) = terminal::size().unwrap();
+        let buffer = render_preview_buffer(
+            &labels[idx],
+            &selections[idx],
+            free_square,
+            term_w as usize,
+            term_h as usize,
+            size,
+        );
+
+        queue!(out, terminal::Clear(ClearType::All)).unwrap();
+        for y in 0..buffer.height {
+            queue!(out, cursor::MoveTo(0, y as u16)).unwrap();
+            for x in 0..buffer.width {
+                let cell = buffer.cells[y * buffer.width + x];
+                queue!(
+                    out,
+                    SetForegroundColor(cell.fg),
+                    SetBackgroundColor(cell.bg),
+                    SetAttribute(if cell.bold {
+                        crossterm::style::Attribute::Bold
+                    } else {
+                        crossterm::style::Attribute::NormalIntensity
+                    }),
+                    Print(cell.ch)
+                )
                 .unwrap();
+            }
+        }
+
+        queue!(
+            out,
+            ResetColor,
+            cursor::MoveTo(0, buffer.height as u16 + 1),
+            Print(format!(
+                "[{}/{}] {}  <- / -> switch card, r reshuffle, Enter/q to commit",
+                idx + 1,
+                labels.len(),
+                labels[idx]
+            ))
+        )
+        .unwrap();
+        out.flush().unwrap();
+
+        if let Event::Key(key_event) = event::read().unwrap() {
+            match key_event.code {
+                KeyCode::Left => idx = idx.checked_sub(1).unwrap_or(labels.len() - 1),
+                KeyCode::Right => idx = (idx + 1) % labels.len(),
+                KeyCode::Char('r') => {
+                    selections[idx] = select_diverse_tiles(pool, dist, tiles_per_card(size), rng)
+                }
+                KeyCode::Enter | KeyCode::Char('q') | KeyCode::Esc => break,
+                _ => {}
+            }
         }
     }
+
+    execute!(out, cursor::Show, terminal::LeaveAlternateScreen).unwrap();
+    terminal::disable_raw_mode().unwrap();
 }